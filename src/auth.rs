@@ -0,0 +1,191 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use axum::body::Body;
+use axum::extract::State;
+use axum::http::{Request, StatusCode};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Json as ResponseJson, Response};
+use base64::{Engine as _, engine::general_purpose};
+use sha2::{Digest, Sha256};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+
+use crate::dtos::ApiResponse;
+use crate::state::AppState;
+
+const MAX_CLOCK_SKEW: Duration = Duration::from_secs(300);
+const NONCE_TTL: Duration = Duration::from_secs(300);
+const MAX_BODY_BYTES: usize = 1024 * 1024;
+
+#[derive(Clone)]
+pub struct AuthConfig {
+    pub required: bool,
+    pub allowed_pubkeys: Vec<String>,
+}
+
+impl AuthConfig {
+    pub fn from_env() -> Self {
+        let required = std::env::var("AUTH_REQUIRED")
+            .map(|v| v == "true" || v == "1")
+            .unwrap_or(false);
+
+        let allowed_pubkeys = std::env::var("AUTH_ALLOWED_PUBKEYS")
+            .map(|v| v.split(',').map(|s| s.trim().to_string()).collect())
+            .unwrap_or_default();
+
+        Self {
+            required,
+            allowed_pubkeys,
+        }
+    }
+
+    fn permits(&self, pubkey: &str) -> bool {
+        self.allowed_pubkeys.is_empty() || self.allowed_pubkeys.iter().any(|k| k == pubkey)
+    }
+}
+
+#[derive(Default)]
+pub struct NonceCache {
+    seen: Mutex<HashMap<(String, String), SystemTime>>,
+}
+
+impl NonceCache {
+    fn check_and_insert(&self, pubkey: &str, nonce: &str) -> bool {
+        let mut seen = self.seen.lock().unwrap();
+        let now = SystemTime::now();
+        seen.retain(|_, inserted_at| {
+            now.duration_since(*inserted_at).unwrap_or_default() < NONCE_TTL
+        });
+
+        let key = (pubkey.to_string(), nonce.to_string());
+        if seen.contains_key(&key) {
+            false
+        } else {
+            seen.insert(key, now);
+            true
+        }
+    }
+}
+
+fn hex_encode(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+fn unauthorized(message: &str) -> Response {
+    (
+        StatusCode::UNAUTHORIZED,
+        ResponseJson(ApiResponse::<()>::error(message.to_string())),
+    )
+        .into_response()
+}
+
+pub async fn require_signature(
+    State(state): State<AppState>,
+    req: Request<Body>,
+    next: Next,
+) -> Response {
+    if !state.auth_config.required {
+        return next.run(req).await;
+    }
+
+    let (parts, body) = req.into_parts();
+
+    let signature_header = parts
+        .headers
+        .get("Signature")
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_string);
+    let key_header = parts
+        .headers
+        .get("Key")
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_string);
+    let date_header = parts
+        .headers
+        .get("Date")
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_string);
+    let nonce_header = parts
+        .headers
+        .get("Nonce")
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_string);
+
+    let (signature_b64, pubkey_str, date_str, nonce) =
+        match (signature_header, key_header, date_header, nonce_header) {
+            (Some(sig), Some(key), Some(date), Some(nonce)) => (sig, key, date, nonce),
+            _ => return unauthorized("Missing authentication headers"),
+        };
+
+    let pubkey = match Pubkey::from_str(&pubkey_str) {
+        Ok(key) => key,
+        Err(_) => return unauthorized("Invalid public key"),
+    };
+
+    if !state.auth_config.permits(&pubkey_str) {
+        return unauthorized("Public key not allowed");
+    }
+
+    let request_time = match date_str.parse::<i64>().ok().and_then(|secs| {
+        UNIX_EPOCH.checked_add(Duration::from_secs(secs.max(0) as u64))
+    }) {
+        Some(time) => time,
+        None => return unauthorized("Invalid Date header"),
+    };
+
+    let now = SystemTime::now();
+    let skew = now
+        .duration_since(request_time)
+        .or_else(|_| request_time.duration_since(now))
+        .unwrap_or(Duration::MAX);
+    if skew > MAX_CLOCK_SKEW {
+        return unauthorized("Stale request timestamp");
+    }
+
+    if !state.nonce_cache.check_and_insert(&pubkey_str, &nonce) {
+        return unauthorized("Replayed nonce");
+    }
+
+    let body_bytes = match axum::body::to_bytes(body, MAX_BODY_BYTES).await {
+        Ok(bytes) => bytes,
+        Err(_) => {
+            return (
+                StatusCode::PAYLOAD_TOO_LARGE,
+                ResponseJson(ApiResponse::<()>::error(
+                    "Request body too large".to_string(),
+                )),
+            )
+                .into_response();
+        }
+    };
+
+    let body_hash = Sha256::digest(&body_bytes);
+    let canonical = format!(
+        "{}\n{}\n{}\n{}\n{}",
+        parts.method,
+        parts.uri.path(),
+        date_str,
+        nonce,
+        hex_encode(&body_hash)
+    );
+
+    let signature_bytes = match general_purpose::STANDARD.decode(&signature_b64) {
+        Ok(bytes) => bytes,
+        Err(_) => return unauthorized("Invalid base64 signature"),
+    };
+
+    let signature = match Signature::try_from(signature_bytes.as_slice()) {
+        Ok(sig) => sig,
+        Err(_) => return unauthorized("Invalid signature format"),
+    };
+
+    if !signature.verify(&pubkey.to_bytes(), canonical.as_bytes()) {
+        return unauthorized("Signature verification failed");
+    }
+
+    let req = Request::from_parts(parts, Body::from(body_bytes));
+    next.run(req).await
+}
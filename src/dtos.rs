@@ -39,6 +39,16 @@ pub struct CreateTokenRequest {
     pub mint_authority: Option<String>,
     pub mint: Option<String>,
     pub decimals: Option<u8>,
+    pub name: Option<String>,
+    pub symbol: Option<String>,
+    pub uri: Option<String>,
+}
+
+#[derive(Serialize)]
+#[serde(untagged)]
+pub enum CreateTokenResponseData {
+    Single(InstructionData),
+    WithMetadata(Vec<InstructionData>),
 }
 
 #[derive(Deserialize)]
@@ -94,6 +104,22 @@ pub struct SendTokenRequest {
     pub mint: Option<String>,
     pub owner: Option<String>,
     pub amount: Option<u64>,
+    #[serde(default)]
+    pub ensure_destination: bool,
+}
+
+#[derive(Serialize)]
+#[serde(untagged)]
+pub enum SendTokenResponseData {
+    Single(InstructionData),
+    WithAtaCreation(Vec<InstructionData>),
+}
+
+#[derive(Deserialize)]
+pub struct CreateAtaRequest {
+    pub funder: Option<String>,
+    pub wallet: Option<String>,
+    pub mint: Option<String>,
 }
 
 #[derive(Serialize)]
@@ -111,15 +137,88 @@ pub struct AccountInfo {
 }
 
 #[derive(Serialize)]
-pub struct TokenTransferData {
-    pub program_id: String,
-    pub accounts: Vec<TokenAccountInfo>,
-    pub instruction_data: String,
+pub struct BalanceData {
+    pub pubkey: String,
+    pub lamports: u64,
+    pub sol: f64,
+}
+
+#[derive(Deserialize)]
+pub struct AirdropRequest {
+    pub pubkey: Option<String>,
+    pub lamports: Option<u64>,
+}
+
+#[derive(Serialize)]
+pub struct AirdropData {
+    pub signature: String,
+}
+
+#[derive(Deserialize)]
+pub struct SendTransactionRequest {
+    pub transaction: Option<String>,
 }
 
 #[derive(Serialize)]
-pub struct TokenAccountInfo {
+pub struct SendTransactionData {
+    pub signature: String,
+}
+
+#[derive(Deserialize)]
+pub struct RawAccountMeta {
     pub pubkey: String,
     #[serde(rename = "isSigner")]
     pub is_signer: bool,
+    #[serde(rename = "isWritable")]
+    pub is_writable: bool,
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "type")]
+pub enum InstructionSpec {
+    #[serde(rename = "sendSol")]
+    SendSol {
+        from: String,
+        to: String,
+        lamports: u64,
+    },
+    #[serde(rename = "sendToken")]
+    SendToken {
+        destination: String,
+        mint: String,
+        owner: String,
+        amount: u64,
+    },
+    #[serde(rename = "raw")]
+    Raw {
+        program_id: String,
+        accounts: Vec<RawAccountMeta>,
+        data: String,
+    },
+}
+
+#[derive(Deserialize)]
+pub struct BuildTransactionRequest {
+    pub instructions: Option<Vec<InstructionSpec>>,
+    #[serde(rename = "feePayer")]
+    pub fee_payer: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct BuildTransactionData {
+    pub message: String,
+    pub signers: Vec<String>,
+}
+
+#[derive(Deserialize)]
+pub struct DecodeInstructionRequest {
+    pub program_id: Option<String>,
+    pub instruction_data: Option<String>,
+    pub accounts: Option<Vec<String>>,
+}
+
+#[derive(Serialize)]
+pub struct ParsedInstruction {
+    pub instruction_type: String,
+    pub fields: serde_json::Value,
 }
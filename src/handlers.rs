@@ -1,15 +1,32 @@
-use axum::{extract::Json, http::StatusCode, response::Json as ResponseJson};
+use axum::{
+    extract::{Json, Path, State},
+    http::StatusCode,
+    response::Json as ResponseJson,
+};
 use base64::{Engine as _, engine::general_purpose};
-use solana_program::system_instruction;
+use solana_program::system_instruction::{self, SystemInstruction};
+use solana_program::system_program;
+use solana_sdk::instruction::{AccountMeta, Instruction};
+use solana_sdk::message::Message;
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+use solana_sdk::pubkey::Pubkey;
 use solana_sdk::signature::{Keypair, Signature, Signer};
-use spl_token::instruction as token_instruction;
+use solana_sdk::transaction::Transaction;
+use spl_token::instruction::{self as token_instruction, TokenInstruction};
+use std::collections::HashSet;
 
 use crate::dtos::{
-    ApiResponse, CreateTokenRequest, InstructionData, KeypairData, MintTokenRequest,
-    SendSolRequest, SendTokenRequest, SignMessageData, SignMessageRequest, SolTransferData,
-    TokenAccountInfo, TokenTransferData, VerifyMessageData, VerifyMessageRequest,
+    AirdropData, AirdropRequest, ApiResponse, BalanceData, BuildTransactionData,
+    BuildTransactionRequest, CreateAtaRequest, CreateTokenRequest, CreateTokenResponseData,
+    DecodeInstructionRequest, InstructionData, InstructionSpec, KeypairData, MintTokenRequest,
+    ParsedInstruction, SendSolRequest, SendTokenRequest, SendTokenResponseData,
+    SendTransactionData, SendTransactionRequest, SignMessageData, SignMessageRequest,
+    SolTransferData, VerifyMessageData, VerifyMessageRequest,
 };
 use crate::helper::{instruction_to_response, keypair_from_base58, parse_pubkey};
+use crate::state::AppState;
+
+const DEVNET_GENESIS_HASH: &str = "EtWTRABZaYq6iMfeYKouRu166VU2xqa1wcaWoxPkrZBG";
 
 pub async fn generate_keypair() -> ResponseJson<ApiResponse<KeypairData>> {
     let keypair = Keypair::new();
@@ -142,7 +159,7 @@ pub async fn verify_message(
 
 pub async fn create_token(
     Json(req): Json<CreateTokenRequest>,
-) -> (StatusCode, ResponseJson<ApiResponse<InstructionData>>) {
+) -> (StatusCode, ResponseJson<ApiResponse<CreateTokenResponseData>>) {
     let mint_authority = match &req.mint_authority {
         Some(val) if !val.is_empty() => val,
         _ => {
@@ -211,9 +228,63 @@ pub async fn create_token(
         }
     };
 
+    let name = req.name.as_ref().filter(|s| !s.is_empty());
+    let symbol = req.symbol.as_ref().filter(|s| !s.is_empty());
+    let uri = req.uri.as_ref().filter(|s| !s.is_empty());
+    let metadata_fields_present = name.is_some() as u8 + symbol.is_some() as u8 + uri.is_some() as u8;
+
+    if metadata_fields_present > 0 && metadata_fields_present < 3 {
+        return (
+            StatusCode::BAD_REQUEST,
+            ResponseJson(ApiResponse::error("Missing required fields".to_string())),
+        );
+    }
+
+    if let (Some(name), Some(symbol), Some(uri)) = (name, symbol, uri) {
+        let (metadata_pda, _bump) = Pubkey::find_program_address(
+            &[
+                b"metadata",
+                mpl_token_metadata::id().as_ref(),
+                mint_pubkey.as_ref(),
+            ],
+            &mpl_token_metadata::id(),
+        );
+
+        let metadata_instruction = mpl_token_metadata::instruction::create_metadata_accounts_v3(
+            mpl_token_metadata::id(),
+            metadata_pda,
+            mint_pubkey,
+            mint_authority_pubkey,
+            mint_authority_pubkey,
+            mint_authority_pubkey,
+            name.clone(),
+            symbol.clone(),
+            uri.clone(),
+            None,
+            0,
+            true,
+            true,
+            None,
+            None,
+            None,
+        );
+
+        return (
+            StatusCode::OK,
+            ResponseJson(ApiResponse::success(CreateTokenResponseData::WithMetadata(
+                vec![
+                    instruction_to_response(instruction),
+                    instruction_to_response(metadata_instruction),
+                ],
+            ))),
+        );
+    }
+
     (
         StatusCode::OK,
-        ResponseJson(ApiResponse::success(instruction_to_response(instruction))),
+        ResponseJson(ApiResponse::success(CreateTokenResponseData::Single(
+            instruction_to_response(instruction),
+        ))),
     )
 }
 
@@ -316,7 +387,7 @@ pub async fn send_sol(
 
 pub async fn send_token(
     Json(req): Json<SendTokenRequest>,
-) -> (StatusCode, ResponseJson<ApiResponse<TokenTransferData>>) {
+) -> (StatusCode, ResponseJson<ApiResponse<SendTokenResponseData>>) {
     // Validate required fields
     let destination = match &req.destination {
         Some(val) if !val.is_empty() => val,
@@ -414,23 +485,656 @@ pub async fn send_token(
         }
     };
 
-    let accounts = instruction
-        .accounts
-        .into_iter()
-        .map(|acc| TokenAccountInfo {
-            pubkey: acc.pubkey.to_string(),
-            is_signer: acc.is_signer,
-        })
-        .collect();
+    if req.ensure_destination {
+        let create_ata_instruction =
+            spl_associated_token_account::instruction::create_associated_token_account_idempotent(
+                &owner_pubkey,
+                &destination_pubkey,
+                &mint_pubkey,
+                &spl_token::id(),
+            );
 
-    let response_data = TokenTransferData {
-        program_id: instruction.program_id.to_string(),
-        accounts,
-        instruction_data: general_purpose::STANDARD.encode(&instruction.data),
+        let response_data = vec![
+            instruction_to_response(create_ata_instruction),
+            instruction_to_response(instruction),
+        ];
+
+        return (
+            StatusCode::OK,
+            ResponseJson(ApiResponse::success(SendTokenResponseData::WithAtaCreation(
+                response_data,
+            ))),
+        );
+    }
+
+    (
+        StatusCode::OK,
+        ResponseJson(ApiResponse::success(SendTokenResponseData::Single(
+            instruction_to_response(instruction),
+        ))),
+    )
+}
+
+pub async fn create_ata(
+    Json(req): Json<CreateAtaRequest>,
+) -> (StatusCode, ResponseJson<ApiResponse<InstructionData>>) {
+    let funder = match &req.funder {
+        Some(val) if !val.is_empty() => val,
+        _ => {
+            return (
+                StatusCode::BAD_REQUEST,
+                ResponseJson(ApiResponse::error("Missing required fields".to_string())),
+            );
+        }
+    };
+
+    let wallet = match &req.wallet {
+        Some(val) if !val.is_empty() => val,
+        _ => {
+            return (
+                StatusCode::BAD_REQUEST,
+                ResponseJson(ApiResponse::error("Missing required fields".to_string())),
+            );
+        }
+    };
+
+    let mint = match &req.mint {
+        Some(val) if !val.is_empty() => val,
+        _ => {
+            return (
+                StatusCode::BAD_REQUEST,
+                ResponseJson(ApiResponse::error("Missing required fields".to_string())),
+            );
+        }
+    };
+
+    let funder_pubkey = match parse_pubkey(funder) {
+        Ok(key) => key,
+        Err(err) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                ResponseJson(ApiResponse::error(err)),
+            );
+        }
+    };
+
+    let wallet_pubkey = match parse_pubkey(wallet) {
+        Ok(key) => key,
+        Err(err) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                ResponseJson(ApiResponse::error(err)),
+            );
+        }
+    };
+
+    let mint_pubkey = match parse_pubkey(mint) {
+        Ok(key) => key,
+        Err(err) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                ResponseJson(ApiResponse::error(err)),
+            );
+        }
     };
 
+    let instruction = spl_associated_token_account::instruction::create_associated_token_account(
+        &funder_pubkey,
+        &wallet_pubkey,
+        &mint_pubkey,
+        &spl_token::id(),
+    );
+
     (
         StatusCode::OK,
-        ResponseJson(ApiResponse::success(response_data)),
+        ResponseJson(ApiResponse::success(instruction_to_response(instruction))),
+    )
+}
+
+pub async fn decode_instruction(
+    Json(req): Json<DecodeInstructionRequest>,
+) -> (StatusCode, ResponseJson<ApiResponse<ParsedInstruction>>) {
+    let program_id = match &req.program_id {
+        Some(val) if !val.is_empty() => val,
+        _ => {
+            return (
+                StatusCode::BAD_REQUEST,
+                ResponseJson(ApiResponse::error("Missing required fields".to_string())),
+            );
+        }
+    };
+
+    let instruction_data = match &req.instruction_data {
+        Some(val) if !val.is_empty() => val,
+        _ => {
+            return (
+                StatusCode::BAD_REQUEST,
+                ResponseJson(ApiResponse::error("Missing required fields".to_string())),
+            );
+        }
+    };
+
+    let accounts = match &req.accounts {
+        Some(val) => val,
+        None => {
+            return (
+                StatusCode::BAD_REQUEST,
+                ResponseJson(ApiResponse::error("Missing required fields".to_string())),
+            );
+        }
+    };
+
+    let program_id_pubkey = match parse_pubkey(program_id) {
+        Ok(key) => key,
+        Err(err) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                ResponseJson(ApiResponse::error(err)),
+            );
+        }
+    };
+
+    let data = match general_purpose::STANDARD.decode(instruction_data) {
+        Ok(bytes) => bytes,
+        Err(_) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                ResponseJson(ApiResponse::error(
+                    "Invalid base64 instruction data".to_string(),
+                )),
+            );
+        }
+    };
+
+    let account_pubkeys: Vec<Pubkey> = match accounts.iter().map(|a| parse_pubkey(a)).collect() {
+        Ok(keys) => keys,
+        Err(err) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                ResponseJson(ApiResponse::error(err)),
+            );
+        }
+    };
+
+    let account_at = |idx: usize| -> Result<String, String> {
+        account_pubkeys
+            .get(idx)
+            .map(|pk| pk.to_string())
+            .ok_or_else(|| format!("Account index {idx} out of bounds"))
+    };
+
+    if program_id_pubkey == spl_token::id() {
+        let parsed = match TokenInstruction::unpack(&data) {
+            Ok(instruction) => instruction,
+            Err(_) => {
+                return (
+                    StatusCode::BAD_REQUEST,
+                    ResponseJson(ApiResponse::error("Instruction not parsable".to_string())),
+                );
+            }
+        };
+
+        let result = match parsed {
+            TokenInstruction::InitializeMint {
+                decimals,
+                mint_authority,
+                freeze_authority,
+            } => account_at(0).map(|mint| {
+                (
+                    "InitializeMint",
+                    serde_json::json!({
+                        "mint": mint,
+                        "decimals": decimals,
+                        "mint_authority": mint_authority.to_string(),
+                        "freeze_authority": freeze_authority.map(|a| a.to_string()),
+                    }),
+                )
+            }),
+            TokenInstruction::MintTo { amount } => account_at(0).and_then(|mint| {
+                account_at(1).and_then(|destination| {
+                    account_at(2).map(|authority| {
+                        (
+                            "MintTo",
+                            serde_json::json!({
+                                "mint": mint,
+                                "destination": destination,
+                                "authority": authority,
+                                "amount": amount,
+                            }),
+                        )
+                    })
+                })
+            }),
+            TokenInstruction::Transfer { amount } => account_at(0).and_then(|source| {
+                account_at(1).and_then(|destination| {
+                    account_at(2).map(|authority| {
+                        (
+                            "Transfer",
+                            serde_json::json!({
+                                "source": source,
+                                "destination": destination,
+                                "authority": authority,
+                                "amount": amount,
+                            }),
+                        )
+                    })
+                })
+            }),
+            TokenInstruction::Burn { amount } => account_at(0).and_then(|account| {
+                account_at(1).and_then(|mint| {
+                    account_at(2).map(|authority| {
+                        (
+                            "Burn",
+                            serde_json::json!({
+                                "account": account,
+                                "mint": mint,
+                                "authority": authority,
+                                "amount": amount,
+                            }),
+                        )
+                    })
+                })
+            }),
+            TokenInstruction::CloseAccount => account_at(0).and_then(|account| {
+                account_at(1).and_then(|destination| {
+                    account_at(2).map(|authority| {
+                        (
+                            "CloseAccount",
+                            serde_json::json!({
+                                "account": account,
+                                "destination": destination,
+                                "authority": authority,
+                            }),
+                        )
+                    })
+                })
+            }),
+            _ => Err("Instruction not parsable".to_string()),
+        };
+
+        return match result {
+            Ok((instruction_type, fields)) => (
+                StatusCode::OK,
+                ResponseJson(ApiResponse::success(ParsedInstruction {
+                    instruction_type: instruction_type.to_string(),
+                    fields,
+                })),
+            ),
+            Err(err) => (
+                StatusCode::BAD_REQUEST,
+                ResponseJson(ApiResponse::error(err)),
+            ),
+        };
+    }
+
+    if program_id_pubkey == system_program::id() {
+        let parsed: SystemInstruction = match bincode::deserialize(&data) {
+            Ok(instruction) => instruction,
+            Err(_) => {
+                return (
+                    StatusCode::BAD_REQUEST,
+                    ResponseJson(ApiResponse::error("Instruction not parsable".to_string())),
+                );
+            }
+        };
+
+        let result = match parsed {
+            SystemInstruction::Transfer { lamports } => account_at(0).and_then(|from| {
+                account_at(1).map(|to| {
+                    (
+                        "Transfer",
+                        serde_json::json!({
+                            "from": from,
+                            "to": to,
+                            "lamports": lamports,
+                        }),
+                    )
+                })
+            }),
+            _ => Err("Instruction not parsable".to_string()),
+        };
+
+        return match result {
+            Ok((instruction_type, fields)) => (
+                StatusCode::OK,
+                ResponseJson(ApiResponse::success(ParsedInstruction {
+                    instruction_type: instruction_type.to_string(),
+                    fields,
+                })),
+            ),
+            Err(err) => (
+                StatusCode::BAD_REQUEST,
+                ResponseJson(ApiResponse::error(err)),
+            ),
+        };
+    }
+
+    (
+        StatusCode::BAD_REQUEST,
+        ResponseJson(ApiResponse::error(
+            "Unsupported program for decoding".to_string(),
+        )),
+    )
+}
+
+pub async fn get_balance(
+    State(state): State<AppState>,
+    Path(pubkey): Path<String>,
+) -> (StatusCode, ResponseJson<ApiResponse<BalanceData>>) {
+    let pubkey_parsed = match parse_pubkey(&pubkey) {
+        Ok(key) => key,
+        Err(err) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                ResponseJson(ApiResponse::error(err)),
+            );
+        }
+    };
+
+    let lamports = match state.rpc_client.get_balance(&pubkey_parsed) {
+        Ok(val) => val,
+        Err(err) => {
+            return (
+                StatusCode::BAD_GATEWAY,
+                ResponseJson(ApiResponse::error(err.to_string())),
+            );
+        }
+    };
+
+    (
+        StatusCode::OK,
+        ResponseJson(ApiResponse::success(BalanceData {
+            pubkey: pubkey_parsed.to_string(),
+            lamports,
+            sol: lamports as f64 / LAMPORTS_PER_SOL as f64,
+        })),
+    )
+}
+
+pub async fn airdrop(
+    State(state): State<AppState>,
+    Json(req): Json<AirdropRequest>,
+) -> (StatusCode, ResponseJson<ApiResponse<AirdropData>>) {
+    let pubkey = match &req.pubkey {
+        Some(val) if !val.is_empty() => val,
+        _ => {
+            return (
+                StatusCode::BAD_REQUEST,
+                ResponseJson(ApiResponse::error("Missing required fields".to_string())),
+            );
+        }
+    };
+
+    let lamports = match req.lamports.filter(|v| *v > 0) {
+        Some(val) => val,
+        None => {
+            return (
+                StatusCode::BAD_REQUEST,
+                ResponseJson(ApiResponse::error("Missing required fields".to_string())),
+            );
+        }
+    };
+
+    let pubkey_parsed = match parse_pubkey(pubkey) {
+        Ok(key) => key,
+        Err(err) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                ResponseJson(ApiResponse::error(err)),
+            );
+        }
+    };
+
+    match state.rpc_client.get_genesis_hash() {
+        Ok(hash) if hash.to_string() == DEVNET_GENESIS_HASH => {}
+        Ok(_) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                ResponseJson(ApiResponse::error(
+                    "Airdrop is only available on devnet".to_string(),
+                )),
+            );
+        }
+        Err(err) => {
+            return (
+                StatusCode::BAD_GATEWAY,
+                ResponseJson(ApiResponse::error(err.to_string())),
+            );
+        }
+    }
+
+    let signature = match state.rpc_client.request_airdrop(&pubkey_parsed, lamports) {
+        Ok(sig) => sig,
+        Err(err) => {
+            return (
+                StatusCode::BAD_GATEWAY,
+                ResponseJson(ApiResponse::error(err.to_string())),
+            );
+        }
+    };
+
+    (
+        StatusCode::OK,
+        ResponseJson(ApiResponse::success(AirdropData {
+            signature: signature.to_string(),
+        })),
+    )
+}
+
+pub async fn send_transaction(
+    State(state): State<AppState>,
+    Json(req): Json<SendTransactionRequest>,
+) -> (StatusCode, ResponseJson<ApiResponse<SendTransactionData>>) {
+    let transaction_b64 = match &req.transaction {
+        Some(val) if !val.is_empty() => val,
+        _ => {
+            return (
+                StatusCode::BAD_REQUEST,
+                ResponseJson(ApiResponse::error("Missing required fields".to_string())),
+            );
+        }
+    };
+
+    let transaction_bytes = match general_purpose::STANDARD.decode(transaction_b64) {
+        Ok(bytes) => bytes,
+        Err(_) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                ResponseJson(ApiResponse::error(
+                    "Invalid base64 transaction".to_string(),
+                )),
+            );
+        }
+    };
+
+    let transaction: Transaction = match bincode::deserialize(&transaction_bytes) {
+        Ok(tx) => tx,
+        Err(_) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                ResponseJson(ApiResponse::error("Invalid transaction encoding".to_string())),
+            );
+        }
+    };
+
+    let signature = match state.rpc_client.send_and_confirm_transaction(&transaction) {
+        Ok(sig) => sig,
+        Err(err) => {
+            return (
+                StatusCode::BAD_GATEWAY,
+                ResponseJson(ApiResponse::error(err.to_string())),
+            );
+        }
+    };
+
+    (
+        StatusCode::OK,
+        ResponseJson(ApiResponse::success(SendTransactionData {
+            signature: signature.to_string(),
+        })),
+    )
+}
+
+fn instruction_spec_to_instruction(spec: &InstructionSpec) -> Result<Instruction, String> {
+    match spec {
+        InstructionSpec::SendSol { from, to, lamports } => {
+            let from_pubkey = parse_pubkey(from)?;
+            let to_pubkey = parse_pubkey(to)?;
+            Ok(system_instruction::transfer(
+                &from_pubkey,
+                &to_pubkey,
+                *lamports,
+            ))
+        }
+        InstructionSpec::SendToken {
+            destination,
+            mint,
+            owner,
+            amount,
+        } => {
+            let mint_pubkey = parse_pubkey(mint)?;
+            let owner_pubkey = parse_pubkey(owner)?;
+            let destination_pubkey = parse_pubkey(destination)?;
+
+            let source_ata = spl_associated_token_account::get_associated_token_address(
+                &owner_pubkey,
+                &mint_pubkey,
+            );
+            let dest_ata = spl_associated_token_account::get_associated_token_address(
+                &destination_pubkey,
+                &mint_pubkey,
+            );
+
+            token_instruction::transfer(
+                &spl_token::id(),
+                &source_ata,
+                &dest_ata,
+                &owner_pubkey,
+                &[],
+                *amount,
+            )
+            .map_err(|_| "Failed to create transfer instruction".to_string())
+        }
+        InstructionSpec::Raw {
+            program_id,
+            accounts,
+            data,
+        } => {
+            let program_id_pubkey = parse_pubkey(program_id)?;
+            let data_bytes = general_purpose::STANDARD
+                .decode(data)
+                .map_err(|_| "Invalid base64 instruction data".to_string())?;
+
+            let account_metas = accounts
+                .iter()
+                .map(|acc| {
+                    parse_pubkey(&acc.pubkey).map(|pubkey| AccountMeta {
+                        pubkey,
+                        is_signer: acc.is_signer,
+                        is_writable: acc.is_writable,
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            Ok(Instruction {
+                program_id: program_id_pubkey,
+                accounts: account_metas,
+                data: data_bytes,
+            })
+        }
+    }
+}
+
+pub async fn build_transaction(
+    State(state): State<AppState>,
+    Json(req): Json<BuildTransactionRequest>,
+) -> (StatusCode, ResponseJson<ApiResponse<BuildTransactionData>>) {
+    let specs = match &req.instructions {
+        Some(val) if !val.is_empty() => val,
+        _ => {
+            return (
+                StatusCode::BAD_REQUEST,
+                ResponseJson(ApiResponse::error("Missing required fields".to_string())),
+            );
+        }
+    };
+
+    let fee_payer = match &req.fee_payer {
+        Some(val) if !val.is_empty() => val,
+        _ => {
+            return (
+                StatusCode::BAD_REQUEST,
+                ResponseJson(ApiResponse::error("Missing required fields".to_string())),
+            );
+        }
+    };
+
+    let fee_payer_pubkey = match parse_pubkey(fee_payer) {
+        Ok(key) => key,
+        Err(err) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                ResponseJson(ApiResponse::error(err)),
+            );
+        }
+    };
+
+    let instructions = match specs
+        .iter()
+        .map(instruction_spec_to_instruction)
+        .collect::<Result<Vec<_>, _>>()
+    {
+        Ok(val) => val,
+        Err(err) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                ResponseJson(ApiResponse::error(err)),
+            );
+        }
+    };
+
+    let blockhash = match state.rpc_client.get_latest_blockhash() {
+        Ok(hash) => hash,
+        Err(err) => {
+            return (
+                StatusCode::BAD_GATEWAY,
+                ResponseJson(ApiResponse::error(err.to_string())),
+            );
+        }
+    };
+
+    let message =
+        Message::new_with_blockhash(&instructions, Some(&fee_payer_pubkey), &blockhash);
+
+    let mut signers = vec![fee_payer_pubkey.to_string()];
+    let mut seen: HashSet<String> = signers.iter().cloned().collect();
+    for instruction in &instructions {
+        for account in &instruction.accounts {
+            if account.is_signer {
+                let key = account.pubkey.to_string();
+                if seen.insert(key.clone()) {
+                    signers.push(key);
+                }
+            }
+        }
+    }
+
+    let transaction = Transaction::new_unsigned(message);
+    let message_bytes = match bincode::serialize(&transaction.message) {
+        Ok(bytes) => bytes,
+        Err(_) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                ResponseJson(ApiResponse::error("Failed to serialize message".to_string())),
+            );
+        }
+    };
+
+    (
+        StatusCode::OK,
+        ResponseJson(ApiResponse::success(BuildTransactionData {
+            message: general_purpose::STANDARD.encode(&message_bytes),
+            signers,
+        })),
     )
 }
@@ -0,0 +1,50 @@
+mod auth;
+mod dtos;
+mod handlers;
+mod helper;
+mod state;
+
+use axum::{
+    Router, middleware,
+    routing::{get, post},
+};
+
+use handlers::{
+    airdrop, build_transaction, create_ata, create_token, decode_instruction, generate_keypair,
+    get_balance, mint_token, send_sol, send_token, send_transaction, sign_message,
+    verify_message,
+};
+use state::AppState;
+
+#[tokio::main]
+async fn main() {
+    let state = AppState::new();
+
+    let app = Router::new()
+        .route("/keypair", post(generate_keypair))
+        .route("/message/sign", post(sign_message))
+        .route("/message/verify", post(verify_message))
+        .route("/token/create", post(create_token))
+        .route("/token/mint", post(mint_token))
+        .route("/send/sol", post(send_sol))
+        .route("/send/token", post(send_token))
+        .route("/create-ata", post(create_ata))
+        .route("/decode-instruction", post(decode_instruction))
+        .route("/balance/:pubkey", get(get_balance))
+        .route("/airdrop", post(airdrop))
+        .route("/send-transaction", post(send_transaction))
+        .route("/build-transaction", post(build_transaction))
+        .layer(middleware::from_fn_with_state(
+            state.clone(),
+            auth::require_signature,
+        ))
+        .with_state(state);
+
+    let listener = tokio::net::TcpListener::bind("0.0.0.0:8080")
+        .await
+        .expect("failed to bind server address");
+
+    axum::serve(listener, app)
+        .await
+        .expect("server error");
+}
@@ -0,0 +1,31 @@
+use std::sync::Arc;
+
+use solana_client::rpc_client::RpcClient;
+
+use crate::auth::{AuthConfig, NonceCache};
+
+#[derive(Clone)]
+pub struct AppState {
+    pub rpc_client: Arc<RpcClient>,
+    pub auth_config: Arc<AuthConfig>,
+    pub nonce_cache: Arc<NonceCache>,
+}
+
+impl AppState {
+    pub fn new() -> Self {
+        let cluster_url = std::env::var("SOLANA_RPC_URL")
+            .unwrap_or_else(|_| "https://api.devnet.solana.com".to_string());
+
+        Self {
+            rpc_client: Arc::new(RpcClient::new(cluster_url)),
+            auth_config: Arc::new(AuthConfig::from_env()),
+            nonce_cache: Arc::new(NonceCache::default()),
+        }
+    }
+}
+
+impl Default for AppState {
+    fn default() -> Self {
+        Self::new()
+    }
+}